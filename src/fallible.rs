@@ -0,0 +1,379 @@
+//! A first-class `FallibleIterator` trait whose `next()` returns `Result<Option<Item>, Error>` instead of `Option<Result<Item, Error>>`.
+//! See documentation for [FallibleIterator] for details.
+//!
+//! The rest of this crate is built around the `Iterator<Item = Result<T, E>>` model, which has a well-known footgun: standard adapters like [count()](std::iter::Iterator::count()), [last()](std::iter::Iterator::last()), or [sum()](std::iter::Iterator::sum()) have no notion of `Err` and will happily keep counting (or looping on) error items instead of stopping at the first one.  `FallibleIterator` hoists the error out of `Item` and into the signature of `next()` itself, so every provided method is error-aware by construction.  Use [from_result_iter()] to opt an existing `Iterator<Item = Result<T, E>>` into this stronger model, and [FallibleIterator::results()] to go back the other way when interoperating with [flatten_results()](crate::FlattenResults::flatten_results()) or [stop_after_error()](crate::StopAfterError::stop_after_error()).
+
+/// An iterator whose iteration itself is fallible: [next()](FallibleIterator::next()) returns `Result<Option<Item>, Error>` rather than `Option<Result<Item, Error>>`.  This makes it impossible to accidentally run an error-blind adapter like [count()](std::iter::Iterator::count()) over a stream that can fail; every provided method below short-circuits on the first `Err`.
+///
+/// Example:
+///
+/// ```
+/// # struct MyError;
+/// # impl std::fmt::Display for MyError {
+/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// # 		write!(f, "MyError")
+/// # 	}
+/// # }
+/// # impl std::fmt::Debug for MyError {
+/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// # 		<MyError as std::fmt::Display>::fmt(self, f)
+/// # 	}
+/// # }
+/// # impl std::error::Error for MyError { }
+/// use resultit::fallible::{FallibleIterator, from_result_iter};
+///
+/// // Counting with the standard Iterator adapter counts the error too.
+/// let v: Vec<Result<i32, MyError>> = vec![Ok(1), Ok(2), Err(MyError{}), Ok(3)];
+/// assert_eq!(v.into_iter().count(), 4);
+///
+/// // Counting with FallibleIterator stops at (and reports) the first error.
+/// let v: Vec<Result<i32, MyError>> = vec![Ok(1), Ok(2), Err(MyError{}), Ok(3)];
+/// assert!(from_result_iter(v.into_iter()).count().is_err());
+/// ```
+pub trait FallibleIterator {
+	/// Type of item yielded on success.
+	type Item;
+
+	/// Type of error that can terminate iteration.
+	type Error;
+
+	/// Advance the iterator, returning `Ok(Some(item))` for the next item, `Ok(None)` once the iterator is exhausted, or `Err(e)` if producing the next item failed.
+	fn next(&mut self) -> Result<Option<Self::Item>, Self::Error>;
+
+	/// Count the remaining items, stopping at (and returning) the first error instead of counting it as an item.
+	fn count(mut self) -> Result<usize, Self::Error>
+	where
+		Self: Sized,
+	{
+		let mut n = 0;
+		while self.next()?.is_some() {
+			n += 1;
+		}
+		Ok(n)
+	}
+
+	/// Consume the iterator, returning the last item, or an error if one was encountered before the iterator was exhausted.
+	///
+	/// ```
+	/// use resultit::fallible::{FallibleIterator, from_result_iter};
+	/// # struct MyError;
+	/// # impl std::fmt::Display for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		write!(f, "MyError")
+	/// # 	}
+	/// # }
+	/// # impl std::fmt::Debug for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		<MyError as std::fmt::Display>::fmt(self, f)
+	/// # 	}
+	/// # }
+	/// # impl std::error::Error for MyError { }
+	/// let v: Vec<Result<i32, MyError>> = vec![Ok(1), Ok(2), Ok(3)];
+	/// assert_eq!(from_result_iter(v.into_iter()).last().unwrap(), Some(3));
+	///
+	/// let v: Vec<Result<i32, MyError>> = vec![Ok(1), Err(MyError{}), Ok(3)];
+	/// assert!(from_result_iter(v.into_iter()).last().is_err());
+	/// ```
+	fn last(mut self) -> Result<Option<Self::Item>, Self::Error>
+	where
+		Self: Sized,
+	{
+		let mut last = None;
+		while let Some(item) = self.next()? {
+			last = Some(item);
+		}
+		Ok(last)
+	}
+
+	/// Fold the remaining items into an accumulator, stopping at the first error.
+	///
+	/// ```
+	/// use resultit::fallible::{FallibleIterator, from_result_iter};
+	/// # struct MyError;
+	/// # impl std::fmt::Display for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		write!(f, "MyError")
+	/// # 	}
+	/// # }
+	/// # impl std::fmt::Debug for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		<MyError as std::fmt::Display>::fmt(self, f)
+	/// # 	}
+	/// # }
+	/// # impl std::error::Error for MyError { }
+	/// let v: Vec<Result<i32, MyError>> = vec![Ok(1), Ok(2), Ok(3)];
+	/// assert_eq!(from_result_iter(v.into_iter()).fold(0, |acc, i| acc + i).unwrap(), 6);
+	/// ```
+	fn fold<B, F>(mut self, init: B, mut f: F) -> Result<B, Self::Error>
+	where
+		Self: Sized,
+		F: FnMut(B, Self::Item) -> B,
+	{
+		let mut acc = init;
+		while let Some(item) = self.next()? {
+			acc = f(acc, item);
+		}
+		Ok(acc)
+	}
+
+	/// Call `f` on each remaining item, stopping at the first error.
+	///
+	/// ```
+	/// use resultit::fallible::{FallibleIterator, from_result_iter};
+	/// # struct MyError;
+	/// # impl std::fmt::Display for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		write!(f, "MyError")
+	/// # 	}
+	/// # }
+	/// # impl std::fmt::Debug for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		<MyError as std::fmt::Display>::fmt(self, f)
+	/// # 	}
+	/// # }
+	/// # impl std::error::Error for MyError { }
+	/// let mut seen = Vec::new();
+	/// let v: Vec<Result<i32, MyError>> = vec![Ok(1), Ok(2), Ok(3)];
+	/// from_result_iter(v.into_iter()).for_each(|i| seen.push(i)).unwrap();
+	/// assert_eq!(seen, vec![1, 2, 3]);
+	/// ```
+	fn for_each<F>(mut self, mut f: F) -> Result<(), Self::Error>
+	where
+		Self: Sized,
+		F: FnMut(Self::Item),
+	{
+		while let Some(item) = self.next()? {
+			f(item);
+		}
+		Ok(())
+	}
+
+	/// Collect the remaining items into `B`, stopping at the first error.
+	///
+	/// ```
+	/// use resultit::fallible::{FallibleIterator, from_result_iter};
+	/// # struct MyError;
+	/// # impl std::fmt::Display for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		write!(f, "MyError")
+	/// # 	}
+	/// # }
+	/// # impl std::fmt::Debug for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		<MyError as std::fmt::Display>::fmt(self, f)
+	/// # 	}
+	/// # }
+	/// # impl std::error::Error for MyError { }
+	/// let v: Vec<Result<i32, MyError>> = vec![Ok(1), Ok(2), Ok(3)];
+	/// let v: Vec<i32> = from_result_iter(v.into_iter()).collect().unwrap();
+	/// assert_eq!(v, vec![1, 2, 3]);
+	///
+	/// let v: Vec<Result<i32, MyError>> = vec![Ok(1), Err(MyError{}), Ok(3)];
+	/// assert!(from_result_iter(v.into_iter()).collect::<Vec<i32>>().is_err());
+	/// ```
+	fn collect<B>(self) -> Result<B, Self::Error>
+	where
+		Self: Sized,
+		B: std::iter::FromIterator<Self::Item>,
+	{
+		self.results().collect()
+	}
+
+	/// Adapt this iterator by applying `f` to each item.  See [FallibleMap].
+	///
+	/// ```
+	/// use resultit::fallible::{FallibleIterator, from_result_iter};
+	/// # struct MyError;
+	/// # impl std::fmt::Display for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		write!(f, "MyError")
+	/// # 	}
+	/// # }
+	/// # impl std::fmt::Debug for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		<MyError as std::fmt::Display>::fmt(self, f)
+	/// # 	}
+	/// # }
+	/// # impl std::error::Error for MyError { }
+	/// let v: Vec<Result<i32, MyError>> = vec![Ok(1), Ok(2), Ok(3)];
+	/// let v: Vec<i32> = from_result_iter(v.into_iter()).map(|i| i * 2).collect().unwrap();
+	/// assert_eq!(v, vec![2, 4, 6]);
+	/// ```
+	fn map<O, F>(self, f: F) -> FallibleMap<Self, F>
+	where
+		Self: Sized,
+		F: FnMut(Self::Item) -> O,
+	{
+		FallibleMap { iter: self, f }
+	}
+
+	/// Adapt this iterator, keeping only items for which `f` returns `true`.  See [FallibleFilter].
+	///
+	/// ```
+	/// use resultit::fallible::{FallibleIterator, from_result_iter};
+	/// # struct MyError;
+	/// # impl std::fmt::Display for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		write!(f, "MyError")
+	/// # 	}
+	/// # }
+	/// # impl std::fmt::Debug for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		<MyError as std::fmt::Display>::fmt(self, f)
+	/// # 	}
+	/// # }
+	/// # impl std::error::Error for MyError { }
+	/// let v: Vec<Result<i32, MyError>> = vec![Ok(1), Ok(2), Ok(3), Ok(4)];
+	/// let v: Vec<i32> = from_result_iter(v.into_iter()).filter(|i| i % 2 == 0).collect().unwrap();
+	/// assert_eq!(v, vec![2, 4]);
+	/// ```
+	fn filter<F>(self, f: F) -> FallibleFilter<Self, F>
+	where
+		Self: Sized,
+		F: FnMut(&Self::Item) -> bool,
+	{
+		FallibleFilter { iter: self, f }
+	}
+
+	/// Adapt this `FallibleIterator` back into a regular `Iterator<Item = Result<Self::Item, Self::Error>>`, so it can interoperate with the rest of the crate (e.g. [flatten_results()](crate::FlattenResults::flatten_results()), [stop_after_error()](crate::StopAfterError::stop_after_error())).  See [ResultsIter].
+	///
+	/// ```
+	/// use resultit::fallible::{FallibleIterator, from_result_iter};
+	/// # struct MyError;
+	/// # impl std::fmt::Display for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		write!(f, "MyError")
+	/// # 	}
+	/// # }
+	/// # impl std::fmt::Debug for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		<MyError as std::fmt::Display>::fmt(self, f)
+	/// # 	}
+	/// # }
+	/// # impl std::error::Error for MyError { }
+	/// let v: Vec<Result<i32, MyError>> = vec![Ok(1), Ok(2), Err(MyError{}), Ok(3)];
+	/// let v: Vec<Result<i32, MyError>> = from_result_iter(v.into_iter()).results().collect();
+	/// println!("{:?}", v);
+	/// // [Ok(1), Ok(2), Err(MyError)]
+	/// assert_eq!(v.len(), 3);
+	/// assert_eq!(*v[0].as_ref().unwrap(), 1);
+	/// assert_eq!(*v[1].as_ref().unwrap(), 2);
+	/// assert!(v[2].is_err());
+	/// ```
+	fn results(self) -> ResultsIter<Self>
+	where
+		Self: Sized,
+	{
+		ResultsIter {
+			iter: self,
+			done: false,
+		}
+	}
+}
+
+/// Iterator adapter returned by [FallibleIterator::map()].  You should not need to use this directly.
+pub struct FallibleMap<It, F> {
+	// Iterator we are wrapping.
+	iter: It,
+
+	// Closure applied to each item.
+	f: F,
+}
+
+impl<It, O, F> FallibleIterator for FallibleMap<It, F>
+where
+	It: FallibleIterator,
+	F: FnMut(It::Item) -> O,
+{
+	type Item = O;
+	type Error = It::Error;
+
+	fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+		Ok(self.iter.next()?.map(|item| (self.f)(item)))
+	}
+}
+
+/// Iterator adapter returned by [FallibleIterator::filter()].  You should not need to use this directly.
+pub struct FallibleFilter<It, F> {
+	// Iterator we are wrapping.
+	iter: It,
+
+	// Predicate applied to each item.
+	f: F,
+}
+
+impl<It, F> FallibleIterator for FallibleFilter<It, F>
+where
+	It: FallibleIterator,
+	F: FnMut(&It::Item) -> bool,
+{
+	type Item = It::Item;
+	type Error = It::Error;
+
+	fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+		loop {
+			match self.iter.next()? {
+				None => return Ok(None),
+				Some(item) => {
+					if (self.f)(&item) {
+						return Ok(Some(item));
+					}
+					// Predicate rejected this item, keep looking.
+				}
+			}
+		}
+	}
+}
+
+/// Adapter returned by [from_result_iter()] that wraps a regular `Iterator<Item = Result<T, E>>` as a [FallibleIterator].  You should not need to use this directly.
+pub struct FromResultIter<It> {
+	// Iterator we are wrapping.
+	iter: It,
+}
+
+impl<It, T, E> FallibleIterator for FromResultIter<It>
+where
+	It: Iterator<Item = Result<T, E>>,
+{
+	type Item = T;
+	type Error = E;
+
+	fn next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+		self.iter.next().transpose()
+	}
+}
+
+/// Wrap any `Iterator<Item = Result<T, E>>` into a [FallibleIterator], so its provided methods (`count`, `last`, `fold`, `for_each`, `collect`, `map`, `filter`) are error-aware.  See the documentation for [FallibleIterator].
+pub fn from_result_iter<It, T, E>(iter: It) -> FromResultIter<It>
+where
+	It: Iterator<Item = Result<T, E>>,
+{
+	FromResultIter { iter }
+}
+
+/// Iterator returned by [FallibleIterator::results()].  You should not need to use this directly.  Yields `Ok(item)` for every item produced by the wrapped [FallibleIterator], then a single `Err(e)` if it fails, then `None` forever after (mirroring [StopAfterErrorIter](crate::stop_after_error::StopAfterErrorIter)) — even if the wrapped [FallibleIterator] would otherwise keep producing items past an error.
+pub struct ResultsIter<It> {
+	// FallibleIterator we are wrapping.
+	iter: It,
+
+	// Set to true once the wrapped FallibleIterator has yielded an error.
+	done: bool,
+}
+
+impl<It> Iterator for ResultsIter<It>
+where
+	It: FallibleIterator,
+{
+	type Item = Result<It::Item, It::Error>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		let next = self.iter.next();
+		if next.is_err() {
+			self.done = true;
+		}
+		next.transpose()
+	}
+}
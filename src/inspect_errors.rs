@@ -0,0 +1,130 @@
+//! Iterator adapters to divert errors to a sink while passing `Ok` values through, instead of aborting on the first error the way `collect::<Result<_,_>>()` does.
+//! See documentation for [DivertErrors] for details.
+
+/// Destination for errors diverted out of an `Iterator<Item = Result<O, E>>` by [divert_errors()](DivertErrors::divert_errors()).  Implemented for `FnMut(E)` closures and for `&mut Vec<E>`, so callers can divert into whichever is more convenient.
+pub trait ErrorSink<E> {
+	/// Record a diverted error.
+	fn sink(&mut self, error: E);
+}
+
+impl<E, F> ErrorSink<E> for F
+where
+	F: FnMut(E),
+{
+	fn sink(&mut self, error: E) {
+		self(error)
+	}
+}
+
+impl<E> ErrorSink<E> for &mut Vec<E> {
+	fn sink(&mut self, error: E) {
+		self.push(error)
+	}
+}
+
+/// Family of iterator adapters for continuing past errors in an `Iterator<Item = Result<O, E>>` while still capturing every error encountered, useful when flattening large fallible iterators for batch jobs where a single bad record shouldn't abort the whole run.  Use this trait to enable [divert_errors()](DivertErrors::divert_errors()) and [try_collect_with_errors()](DivertErrors::try_collect_with_errors()) on iterators.
+///
+/// Example:
+///
+/// ```
+/// # struct MyError;
+/// # impl std::fmt::Display for MyError {
+/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// # 		write!(f, "MyError")
+/// # 	}
+/// # }
+/// # impl std::fmt::Debug for MyError {
+/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// # 		<MyError as std::fmt::Display>::fmt(self, f)
+/// # 	}
+/// # }
+/// # impl std::error::Error for MyError { }
+/// use resultit::DivertErrors;
+///
+/// let v: Vec<Result<i32, MyError>> = vec![Ok(1), Err(MyError{}), Ok(2), Err(MyError{}), Ok(3)];
+///
+/// // Divert errors into a Vec, keep processing the Ok values.
+/// let mut errors: Vec<MyError> = Vec::new();
+/// let oks: Vec<i32> = v.into_iter().divert_errors(&mut errors).collect();
+/// assert_eq!(oks, vec![1, 2, 3]);
+/// assert_eq!(errors.len(), 2);
+///
+/// // Or collect both halves at once.
+/// let v: Vec<Result<i32, MyError>> = vec![Ok(1), Err(MyError{}), Ok(2), Err(MyError{}), Ok(3)];
+/// let (oks, errors): (Vec<i32>, Vec<MyError>) = v.into_iter().try_collect_with_errors();
+/// assert_eq!(oks, vec![1, 2, 3]);
+/// assert_eq!(errors.len(), 2);
+/// ```
+pub trait DivertErrors {
+	/// Yield only the `Ok` values, pushing each `Err(e)` into `sink` as a side effect instead of stopping.  See the documentation for [DivertErrors].
+	fn divert_errors<O, E, S>(self, sink: S) -> DivertErrorsIter<Self, S>
+	where
+		Self: Iterator<Item = Result<O, E>> + Sized,
+		S: ErrorSink<E>;
+
+	/// Collect all `Ok` values into `B` and simultaneously return every `Err` encountered, instead of aborting on the first one the way `collect::<Result<_,_>>()` does.  See the documentation for [DivertErrors].
+	fn try_collect_with_errors<O, E, B>(self) -> (B, Vec<E>)
+	where
+		Self: Iterator<Item = Result<O, E>> + Sized,
+		B: std::iter::FromIterator<O>;
+}
+
+// Blanket implementation of the DivertErrors trait for all iterators.
+// This is what enables us to call divert_errors() and try_collect_with_errors() on any iterator.
+impl<It> DivertErrors for It
+where
+	It: Iterator + Sized,
+{
+	fn divert_errors<O, E, S>(self, sink: S) -> DivertErrorsIter<Self, S>
+	where
+		Self: Iterator<Item = Result<O, E>> + Sized,
+		S: ErrorSink<E>,
+	{
+		DivertErrorsIter { iter: self, sink }
+	}
+
+	fn try_collect_with_errors<O, E, B>(self) -> (B, Vec<E>)
+	where
+		Self: Iterator<Item = Result<O, E>> + Sized,
+		B: std::iter::FromIterator<O>,
+	{
+		let mut errors = Vec::new();
+		let oks = self
+			.filter_map(|result| match result {
+				Ok(o) => Some(o),
+				Err(e) => {
+					errors.push(e);
+					None
+				}
+			})
+			.collect();
+		(oks, errors)
+	}
+}
+
+/// Iterator returned by [divert_errors()](DivertErrors::divert_errors()).  You should not need to use this directly.  See the documentation for [DivertErrors] for intended use.
+pub struct DivertErrorsIter<It, S> {
+	// Iterator we are wrapping.
+	iter: It,
+
+	// Sink that diverted errors are pushed into.
+	sink: S,
+}
+
+impl<It, O, E, S> Iterator for DivertErrorsIter<It, S>
+where
+	It: Iterator<Item = Result<O, E>>,
+	S: ErrorSink<E>,
+{
+	type Item = O;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			match self.iter.next()? {
+				Ok(o) => return Some(o),
+				Err(e) => self.sink.sink(e),
+				// Error diverted to the sink, keep looking for the next Ok value.
+			}
+		}
+	}
+}
@@ -0,0 +1,121 @@
+//! Lazily-initialized iterator for the common case where both setting up an iterator and driving it can fail.
+//! See documentation for [LazyInit] for details.
+//!
+//! Without this adapter, a function whose setup is fallible AND whose iteration is fallible is forced into the awkward signature `fn foo() -> Result<impl Iterator<Item = Result<T, E>>, E>`, which makes every caller handle the setup error before it can start iterating.  [LazyInit] defers running the initializer until the first call to [next()](std::iter::Iterator::next()), so library authors can instead expose the much cleaner `fn foo() -> impl Iterator<Item = Result<T, E>>` and unify the init and iteration error types into, for example, [TryResult](crate::TryResult).
+
+/// Lazily-initialized iterator.  Defers a fallible setup step until the first call to [next()](std::iter::Iterator::next()), so that both the setup and the iteration it drives can share a single `Result`-yielding iterator instead of forcing callers to unwrap a `Result<impl Iterator, E>` up front.
+///
+/// Constructed with [LazyInit::new()], passing:
+/// - `init: FnOnce() -> Result<I, E>`, which builds the inner iterator `I`, and
+/// - `next: FnMut(&mut I) -> Option<T>`, which drives `I` to produce each item.
+///
+/// On the first call to [next()](std::iter::Iterator::next()), `init` is run.  If it returns `Err(e)`, `LazyInit` yields `Some(Err(e))` once and then fuses to `None` forever after (the same error-latch behavior as [StopAfterErrorIter](crate::stop_after_error::StopAfterErrorIter)).  If it returns `Ok(iter)`, the inner iterator is stored and every subsequent call delegates to `next`.
+///
+/// Example:
+///
+/// ```
+/// # struct MyError;
+/// # impl std::fmt::Display for MyError {
+/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// # 		write!(f, "MyError")
+/// # 	}
+/// # }
+/// # impl std::fmt::Debug for MyError {
+/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// # 		<MyError as std::fmt::Display>::fmt(self, f)
+/// # 	}
+/// # }
+/// # impl std::error::Error for MyError { }
+/// use resultit::lazy_init::LazyInit;
+///
+/// // A library function with both fallible setup and fallible iteration, expressed
+/// // as a single fn foo() -> impl Iterator<Item = Result<T, E>> instead of
+/// // fn foo() -> Result<impl Iterator<Item = Result<T, E>>, E>.
+/// fn foo(fail_init: bool) -> impl Iterator<Item = Result<i32, MyError>> {
+/// 	LazyInit::new(
+/// 		move || -> Result<std::vec::IntoIter<i32>, MyError> {
+/// 			if fail_init {
+/// 				Err(MyError{})
+/// 			} else {
+/// 				Ok(vec![1, 2, 3].into_iter())
+/// 			}
+/// 		},
+/// 		|iter| iter.next()
+/// 	)
+/// }
+///
+/// let v: Vec<Result<i32, MyError>> = foo(false).collect();
+/// println!("{:?}", v);
+/// // [Ok(1), Ok(2), Ok(3)]
+/// # assert_eq!(v.len(), 3);
+/// # assert_eq!(*v[0].as_ref().unwrap(), 1);
+/// # assert_eq!(*v[1].as_ref().unwrap(), 2);
+/// # assert_eq!(*v[2].as_ref().unwrap(), 3);
+///
+/// let v: Vec<Result<i32, MyError>> = foo(true).collect();
+/// println!("{:?}", v);
+/// // [Err(MyError)]
+/// # assert_eq!(v.len(), 1);
+/// # assert_eq!(v[0].is_err(), true);
+/// ```
+pub struct LazyInit<I, F, G> {
+	// Not-yet-run initializer, the initialized inner iterator, or latched after an init error.
+	state: LazyInitState<I, F>,
+
+	// Closure that drives the inner iterator to produce each item.
+	next: G,
+}
+
+// Internal state machine backing LazyInit.
+enum LazyInitState<I, F> {
+	// init() has not yet been called.
+	Uninit(F),
+
+	// init() succeeded; holds the inner iterator.
+	Init(I),
+
+	// init() failed (or state was mid-transition); latches iteration to None.
+	Errored,
+}
+
+impl<I, F, G> LazyInit<I, F, G> {
+	/// Construct a new [LazyInit].  `init` builds the inner iterator on the first call to [next()](std::iter::Iterator::next()); `next` drives the inner iterator thereafter.  See the documentation for [LazyInit].
+	pub fn new<T, E>(init: F, next: G) -> Self
+	where
+		F: FnOnce() -> Result<I, E>,
+		G: FnMut(&mut I) -> Option<T>,
+	{
+		LazyInit {
+			state: LazyInitState::Uninit(init),
+			next,
+		}
+	}
+}
+
+impl<I, T, E, F, G> Iterator for LazyInit<I, F, G>
+where
+	F: FnOnce() -> Result<I, E>,
+	G: FnMut(&mut I) -> Option<T>,
+{
+	type Item = Result<T, E>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			match &mut self.state {
+				LazyInitState::Errored => return None,
+				LazyInitState::Init(iter) => return (self.next)(iter).map(Ok),
+				LazyInitState::Uninit(_) => {
+					// Take the initializer out, latching the error state in case init() panics or fails.
+					let init = match std::mem::replace(&mut self.state, LazyInitState::Errored) {
+						LazyInitState::Uninit(init) => init,
+						_ => unreachable!(),
+					};
+					match init() {
+						Ok(iter) => self.state = LazyInitState::Init(iter),
+						Err(e) => return Some(Err(e)),
+					}
+				}
+			}
+		}
+	}
+}
@@ -115,6 +115,16 @@ pub mod flatten_results;
 pub use flatten_results::FlattenResults;
 pub mod stop_after_error;
 pub use stop_after_error::StopAfterError;
+pub mod transform_results;
+pub use transform_results::TransformResults;
+pub mod fallible;
+pub use fallible::FallibleIterator;
+pub mod lazy_init;
+pub use lazy_init::LazyInit;
+pub mod trip;
+pub use trip::TripOnError;
+pub mod inspect_errors;
+pub use inspect_errors::DivertErrors;
 
 /// Shorthand for a Result with a boxed error trait.
 /// Provided for convenience, not a dependency of any submodule.
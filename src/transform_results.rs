@@ -0,0 +1,270 @@
+//! Iterator adapters to transform the `Ok` and `Err` payload of an `Iterator<Item = Result<O, E>>` without the noise of `.map(|r| r.map(...))`.
+//! See documentation for [TransformResults] for details.
+
+/// Family of iterator adapters for transforming the `Ok` and/or `Err` payload of an `Iterator<Item = Result<O, E>>`, similar in spirit to the [resiter](https://crates.io/crates/resiter) crate.  Use this trait to enable [map_ok()](TransformResults::map_ok()), [map_err()](TransformResults::map_err()), [and_then_ok()](TransformResults::and_then_ok()), and [filter_ok()](TransformResults::filter_ok()) on iterators.  Composes cleanly with [flatten_results()](crate::FlattenResults::flatten_results()) and [stop_after_error()](crate::StopAfterError::stop_after_error()).
+///
+/// Example:
+///
+/// ```
+/// # struct MyError;
+/// # impl std::fmt::Display for MyError {
+/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// # 		write!(f, "MyError")
+/// # 	}
+/// # }
+/// # impl std::fmt::Debug for MyError {
+/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// # 		<MyError as std::fmt::Display>::fmt(self, f)
+/// # 	}
+/// # }
+/// # impl std::error::Error for MyError { }
+/// use resultit::TransformResults;
+///
+/// let v: Vec<Result<i32, MyError>> = vec![Ok(1), Ok(2), Err(MyError{}), Ok(3)];
+///
+/// // Double every Ok value, leaving the Err untouched.
+/// let v: Vec<Result<i32, MyError>> = v.into_iter().map_ok(|o| o * 2).collect();
+/// println!("{:?}", v);
+/// // [Ok(2), Ok(4), Err(MyError), Ok(6)]
+/// # assert_eq!(v.len(), 4);
+/// # assert_eq!(*v[0].as_ref().unwrap(), 2);
+/// # assert_eq!(*v[1].as_ref().unwrap(), 4);
+/// # assert_eq!(v[2].is_err(), true);
+/// # assert_eq!(*v[3].as_ref().unwrap(), 6);
+/// ```
+pub trait TransformResults {
+	/// Apply `f` to the `Ok` payload of each item, passing `Err` through untouched.  See the documentation for [TransformResults].
+	fn map_ok<O, O2, E, F>(self, f: F) -> MapOkIter<Self, F>
+	where
+		Self: Iterator<Item = Result<O, E>> + Sized,
+		F: FnMut(O) -> O2;
+
+	/// Apply `f` to the `Err` payload of each item, passing `Ok` through untouched.  See the documentation for [TransformResults].
+	///
+	/// ```
+	/// # struct MyError;
+	/// # impl std::fmt::Display for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		write!(f, "MyError")
+	/// # 	}
+	/// # }
+	/// # impl std::fmt::Debug for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		<MyError as std::fmt::Display>::fmt(self, f)
+	/// # 	}
+	/// # }
+	/// # impl std::error::Error for MyError { }
+	/// use resultit::TransformResults;
+	///
+	/// let v: Vec<Result<i32, MyError>> = vec![Ok(1), Err(MyError{})];
+	/// let v: Vec<Result<i32, String>> = v.into_iter().map_err(|e| e.to_string()).collect();
+	/// assert_eq!(*v[0].as_ref().unwrap(), 1);
+	/// assert_eq!(*v[1].as_ref().unwrap_err(), "MyError");
+	/// ```
+	fn map_err<O, E, E2, F>(self, f: F) -> MapErrIter<Self, F>
+	where
+		Self: Iterator<Item = Result<O, E>> + Sized,
+		F: FnMut(E) -> E2;
+
+	/// Apply the fallible closure `f` to the `Ok` payload of each item, flattening the resulting `Result` into the output.  `Err` items are passed through untouched.  See the documentation for [TransformResults].
+	///
+	/// ```
+	/// # struct MyError;
+	/// # impl std::fmt::Display for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		write!(f, "MyError")
+	/// # 	}
+	/// # }
+	/// # impl std::fmt::Debug for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		<MyError as std::fmt::Display>::fmt(self, f)
+	/// # 	}
+	/// # }
+	/// # impl std::error::Error for MyError { }
+	/// use resultit::TransformResults;
+	///
+	/// let v: Vec<Result<i32, MyError>> = vec![Ok(2), Ok(-1), Err(MyError{}), Ok(4)];
+	/// let v: Vec<Result<i32, MyError>> = v.into_iter()
+	/// 	.and_then_ok(|o| if o >= 0 { Ok(o * 10) } else { Err(MyError{}) })
+	/// 	.collect();
+	/// assert_eq!(*v[0].as_ref().unwrap(), 20);
+	/// assert!(v[1].is_err());
+	/// assert!(v[2].is_err());
+	/// assert_eq!(*v[3].as_ref().unwrap(), 40);
+	/// ```
+	fn and_then_ok<O, O2, E, F>(self, f: F) -> AndThenOkIter<Self, F>
+	where
+		Self: Iterator<Item = Result<O, E>> + Sized,
+		F: FnMut(O) -> Result<O2, E>;
+
+	/// Keep only the `Ok` items for which `f` returns `true`, discarding the rest.  `Err` items are always passed through untouched.  See the documentation for [TransformResults].
+	///
+	/// ```
+	/// # struct MyError;
+	/// # impl std::fmt::Display for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		write!(f, "MyError")
+	/// # 	}
+	/// # }
+	/// # impl std::fmt::Debug for MyError {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		<MyError as std::fmt::Display>::fmt(self, f)
+	/// # 	}
+	/// # }
+	/// # impl std::error::Error for MyError { }
+	/// use resultit::TransformResults;
+	///
+	/// let v: Vec<Result<i32, MyError>> = vec![Ok(1), Ok(2), Err(MyError{}), Ok(3), Ok(4)];
+	/// let v: Vec<Result<i32, MyError>> = v.into_iter().filter_ok(|o| o % 2 == 0).collect();
+	/// assert_eq!(v.len(), 3);
+	/// assert_eq!(*v[0].as_ref().unwrap(), 2);
+	/// assert!(v[1].is_err());
+	/// assert_eq!(*v[2].as_ref().unwrap(), 4);
+	/// ```
+	fn filter_ok<O, E, F>(self, f: F) -> FilterOkIter<Self, F>
+	where
+		Self: Iterator<Item = Result<O, E>> + Sized,
+		F: FnMut(&O) -> bool;
+}
+
+// Blanket implementation of the TransformResults trait for all iterators.
+// This is what enables us to call map_ok(), map_err(), and_then_ok(), and filter_ok() on any iterator.
+impl<It> TransformResults for It
+where
+	It: Iterator + Sized,
+{
+	fn map_ok<O, O2, E, F>(self, f: F) -> MapOkIter<Self, F>
+	where
+		Self: Iterator<Item = Result<O, E>> + Sized,
+		F: FnMut(O) -> O2,
+	{
+		MapOkIter { iter: self, f }
+	}
+
+	fn map_err<O, E, E2, F>(self, f: F) -> MapErrIter<Self, F>
+	where
+		Self: Iterator<Item = Result<O, E>> + Sized,
+		F: FnMut(E) -> E2,
+	{
+		MapErrIter { iter: self, f }
+	}
+
+	fn and_then_ok<O, O2, E, F>(self, f: F) -> AndThenOkIter<Self, F>
+	where
+		Self: Iterator<Item = Result<O, E>> + Sized,
+		F: FnMut(O) -> Result<O2, E>,
+	{
+		AndThenOkIter { iter: self, f }
+	}
+
+	fn filter_ok<O, E, F>(self, f: F) -> FilterOkIter<Self, F>
+	where
+		Self: Iterator<Item = Result<O, E>> + Sized,
+		F: FnMut(&O) -> bool,
+	{
+		FilterOkIter { iter: self, f }
+	}
+}
+
+/// Iterator returned by [map_ok()](TransformResults::map_ok()).  You should not need to use this directly.  See the documentation for [TransformResults] for intended use.
+pub struct MapOkIter<It, F> {
+	// Iterator we are wrapping.
+	iter: It,
+
+	// Closure applied to the Ok payload of each item.
+	f: F,
+}
+
+impl<It, O, O2, E, F> Iterator for MapOkIter<It, F>
+where
+	It: Iterator<Item = Result<O, E>>,
+	F: FnMut(O) -> O2,
+{
+	type Item = Result<O2, E>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		Some(match self.iter.next()? {
+			Ok(o) => Ok((self.f)(o)),
+			Err(e) => Err(e),
+		})
+	}
+}
+
+/// Iterator returned by [map_err()](TransformResults::map_err()).  You should not need to use this directly.  See the documentation for [TransformResults] for intended use.
+pub struct MapErrIter<It, F> {
+	// Iterator we are wrapping.
+	iter: It,
+
+	// Closure applied to the Err payload of each item.
+	f: F,
+}
+
+impl<It, O, E, E2, F> Iterator for MapErrIter<It, F>
+where
+	It: Iterator<Item = Result<O, E>>,
+	F: FnMut(E) -> E2,
+{
+	type Item = Result<O, E2>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		Some(match self.iter.next()? {
+			Ok(o) => Ok(o),
+			Err(e) => Err((self.f)(e)),
+		})
+	}
+}
+
+/// Iterator returned by [and_then_ok()](TransformResults::and_then_ok()).  You should not need to use this directly.  See the documentation for [TransformResults] for intended use.
+pub struct AndThenOkIter<It, F> {
+	// Iterator we are wrapping.
+	iter: It,
+
+	// Fallible closure applied to the Ok payload of each item.
+	f: F,
+}
+
+impl<It, O, O2, E, F> Iterator for AndThenOkIter<It, F>
+where
+	It: Iterator<Item = Result<O, E>>,
+	F: FnMut(O) -> Result<O2, E>,
+{
+	type Item = Result<O2, E>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		Some(match self.iter.next()? {
+			Ok(o) => (self.f)(o),
+			Err(e) => Err(e),
+		})
+	}
+}
+
+/// Iterator returned by [filter_ok()](TransformResults::filter_ok()).  You should not need to use this directly.  See the documentation for [TransformResults] for intended use.
+pub struct FilterOkIter<It, F> {
+	// Iterator we are wrapping.
+	iter: It,
+
+	// Predicate applied to the Ok payload of each item.
+	f: F,
+}
+
+impl<It, O, E, F> Iterator for FilterOkIter<It, F>
+where
+	It: Iterator<Item = Result<O, E>>,
+	F: FnMut(&O) -> bool,
+{
+	type Item = Result<O, E>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			match self.iter.next()? {
+				Ok(o) => {
+					if (self.f)(&o) {
+						return Some(Ok(o));
+					}
+					// Predicate rejected this Ok value, keep looking.
+				}
+				Err(e) => return Some(Err(e)),
+			}
+		}
+	}
+}
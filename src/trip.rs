@@ -0,0 +1,115 @@
+//! Iterator adapter that trips (pauses) on the first error instead of discarding the rest of the iterator outright.
+//! See documentation for [TripOnError] for details.
+
+/// Iterator adapter that yields items up to and including the first error, then pauses, unlike [stop_after_error()](crate::StopAfterError::stop_after_error()) which latches permanently.  Use this trait to enable [trip_on_error()](TripOnError::trip_on_error()) on iterators.  Supports a circuit-breaker-with-recovery use case: downstream code sees a clean stop at the error, but can inspect it with [tripped_err()](TripIter::tripped_err()) and call [reset()](TripIter::reset()) to resume consuming the remaining items once the error has been logged/handled.
+///
+/// The tripped error is shared between the yielded item and the iterator's own storage via [Arc](std::sync::Arc), so `E` need not implement [Clone](std::clone::Clone) — this keeps `TripIter` usable with error types like the one in [TryResult](crate::TryResult) (`Box<dyn Error + Send + Sync>`) that deliberately don't implement it, and keeps `TripIter` itself `Send`/`Sync` when `E` is.
+///
+/// Example:
+///
+/// ```
+/// # struct MyError;
+/// # impl std::fmt::Display for MyError {
+/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// # 		write!(f, "MyError")
+/// # 	}
+/// # }
+/// # impl std::fmt::Debug for MyError {
+/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// # 		<MyError as std::fmt::Display>::fmt(self, f)
+/// # 	}
+/// # }
+/// # impl std::error::Error for MyError { }
+/// use resultit::trip::TripOnError;
+///
+/// let v: Vec<Result<i32, MyError>> = vec![Ok(1), Ok(2), Err(MyError{}), Ok(3), Ok(4)];
+/// let mut trip = v.into_iter().trip_on_error();
+///
+/// // Yields items up to and including the first error, then pauses.
+/// assert_eq!(*trip.next().unwrap().as_ref().unwrap(), 1);
+/// assert_eq!(*trip.next().unwrap().as_ref().unwrap(), 2);
+/// assert!(trip.next().unwrap().is_err());
+/// assert!(trip.next().is_none());
+///
+/// // The tripped error remains available for inspection.
+/// assert!(trip.tripped_err().is_some());
+///
+/// // Resetting clears the trip so the remaining items can be consumed.
+/// trip.reset();
+/// assert!(trip.tripped_err().is_none());
+/// assert_eq!(*trip.next().unwrap().as_ref().unwrap(), 3);
+/// assert_eq!(*trip.next().unwrap().as_ref().unwrap(), 4);
+/// assert!(trip.next().is_none());
+/// ```
+pub trait TripOnError {
+	/// Iterator adapter that trips (pauses) on the first error instead of discarding the rest of the iterator outright.  See documentation for [TripOnError].
+	fn trip_on_error<O, E>(self) -> TripIter<Self, E>
+	where
+		Self: Iterator<Item = Result<O, E>> + Sized;
+}
+
+// Blanket implementation of the TripOnError trait for all iterators.
+// This is what enables us to call trip_on_error() on any iterator.
+impl<It> TripOnError for It
+where
+	It: Iterator + Sized,
+{
+	fn trip_on_error<O, E>(self) -> TripIter<Self, E>
+	where
+		Self: Iterator<Item = Result<O, E>> + Sized,
+	{
+		TripIter {
+			iter: self,
+			tripped: None,
+		}
+	}
+}
+
+/// Iterator returned by [trip_on_error()](TripOnError::trip_on_error()).  You should not need to construct this directly.  See the documentation for [TripOnError] for intended use.
+pub struct TripIter<It, E> {
+	// Iterator we are wrapping.
+	iter: It,
+
+	// Set to the offending error once the iterator has tripped.  Wrapped in
+	// an Arc so the same error can be both yielded to the caller and kept
+	// around for tripped_err() without requiring E: Clone, while keeping
+	// TripIter Send/Sync for any Send + Sync E (e.g. TryResult's error type).
+	tripped: Option<std::sync::Arc<E>>,
+}
+
+impl<It, E> TripIter<It, E> {
+	/// Inspect the error that tripped this iterator, if it has tripped.
+	pub fn tripped_err(&self) -> Option<&E> {
+		self.tripped.as_deref()
+	}
+
+	/// Clear the trip, allowing the remaining items of the wrapped iterator to be consumed again.
+	pub fn reset(&mut self) {
+		self.tripped = None;
+	}
+}
+
+impl<It, O, E> Iterator for TripIter<It, E>
+where
+	It: Iterator<Item = Result<O, E>>,
+{
+	type Item = Result<O, std::sync::Arc<E>>;
+
+	// Return the next item in iter until (and including) the first error.
+	// Then return None until reset() is called.
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.tripped.is_some() {
+			return None;
+		}
+		match self.iter.next() {
+			None => None,
+			Some(Ok(o)) => Some(Ok(o)),
+			Some(Err(e)) => {
+				// Latch the error for inspection via tripped_err(), and also surface it to the caller.
+				let e = std::sync::Arc::new(e);
+				self.tripped = Some(std::sync::Arc::clone(&e));
+				Some(Err(e))
+			}
+		}
+	}
+}